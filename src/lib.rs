@@ -5,28 +5,77 @@
 extern crate time_test;
 
 use anyhow::Context;
-use std::io::Read;
+use std::collections::VecDeque;
+use std::io::BufRead;
+use std::io::BufReader;
+
+/// The metric to calculate from the TIE input data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    Mtie,
+    Tvar,
+    Tdev,
+}
+
+impl Metric {
+    // The column name / JSON key to print this metric's values under.
+    fn label(self) -> &'static str {
+        match self {
+            Metric::Mtie => "mtie",
+            Metric::Tvar => "tvar",
+            Metric::Tdev => "tdev",
+        }
+    }
+}
+
+/// The format in which to print the result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Plain,
+    Csv,
+    Json,
+}
+
+// The parsed command line arguments.
+struct Arguments {
+    input_filenames: Vec<String>,
+    metric: Metric,
+    format: Format,
+    sample_interval: Option<f64>,
+    log_spaced: bool,
+    tau_points: u32,
+}
 
 /// The entry point for the "library", which implements the mtie application.
 pub fn run() -> anyhow::Result<()> {
-    let input_filename = parse_arguments_for_filename();
-    let input = get_tie_input_data(input_filename).context("failed to get TIE input data")?;
-    let tie = parse_tie_input_data(input);
+    let arguments = parse_arguments_for_filename();
+    let tie = get_tie_input_data(&arguments.input_filenames)
+        .context("failed to get TIE input data")?;
 
     let sample_count = tie.len();
-    let mtie = if sample_count <= 100_000 {
-        mtie_complete(&tie)
-    } else {
-        mtie_fast(&tie)
+    let mtie = match arguments.metric {
+        Metric::Mtie => {
+            if arguments.log_spaced {
+                let taus = log_spaced_taus(sample_count as u32, arguments.tau_points);
+                mtie_windowed(&tie, &taus)
+            } else if sample_count <= 100_000 {
+                mtie_complete(&tie)
+            } else {
+                mtie_fast(&tie)
+            }
+        }
+        Metric::Tvar => tvar(&tie),
+        Metric::Tdev => tdev(&tie),
     };
 
-    print_mtie(&mtie);
+    print_mtie(&mtie, arguments.metric, arguments.format, arguments.sample_interval);
 
     Ok(())
 }
 
-// Parses the command line arguments, returning the input filename, if specified
-fn parse_arguments_for_filename() -> Option<String> {
+// Parses the command line arguments, returning the input filenames (empty if none are given), the metric
+// to calculate, the output format, the sampling interval (if given) and the log-spaced tau options
+fn parse_arguments_for_filename() -> Arguments {
     let long_about = "Calculates MTIE from a series of TIE input data.\n\n\
                       The TIE input data is expected to be in text format, with one number per line.\n\
                       It is assumed that the input data was sampled at a uniform rate.\n\
@@ -35,8 +84,18 @@ fn parse_arguments_for_filename() -> Option<String> {
                       The MTIE is printed to standard output, with each line containing:\n\
                       - an interval\n\
                       - the MTIE for that interval";
-    let input_help = "Specifies the file containing the TIE input data.\n\
+    let input_help = "Specifies one or more files containing the TIE input data, processed in sequence.\n\
+                      Use '-' to read from standard input.\n\
                       If this option is not given, TIE input data is taken from standard input.";
+    let metric_help = "Specifies which metric to calculate: MTIE, TVAR, or TDEV.";
+    let format_help = "Specifies the output format: plain (space-separated columns), csv, or json.";
+    let sample_interval_help = "Specifies the sampling interval of the input data, in seconds.\n\
+                      If given, observation intervals are reported in seconds instead of sample counts.";
+    let log_spaced_help = "Selects a roughly logarithmically-spaced set of observation intervals,\n\
+                      rather than every integer tau, using the exact sliding-window MTIE algorithm.\n\
+                      Only applies to the 'mtie' metric.";
+    let tau_points_help = "Specifies the number of logarithmically-spaced observation intervals per decade.\n\
+                      Only used when --log-spaced is given.";
     let matches = clap::App::new("mtie")
         .version("0.1.0")
         .author("Robin Park <robin.j.park@gmail.com>")
@@ -47,67 +106,213 @@ fn parse_arguments_for_filename() -> Option<String> {
                 .help(input_help)
                 .short("i")
                 .long("input")
+                .takes_value(true)
+                .multiple(true),
+        )
+        .arg(
+            clap::Arg::with_name("metric")
+                .help(metric_help)
+                .long("metric")
+                .takes_value(true)
+                .possible_values(&["mtie", "tvar", "tdev"])
+                .default_value("mtie"),
+        )
+        .arg(
+            clap::Arg::with_name("format")
+                .help(format_help)
+                .long("format")
+                .takes_value(true)
+                .possible_values(&["plain", "csv", "json"])
+                .default_value("plain"),
+        )
+        .arg(
+            clap::Arg::with_name("sample_interval")
+                .help(sample_interval_help)
+                .long("sample-interval")
                 .takes_value(true),
         )
+        .arg(
+            clap::Arg::with_name("log_spaced")
+                .help(log_spaced_help)
+                .long("log-spaced"),
+        )
+        .arg(
+            clap::Arg::with_name("tau_points")
+                .help(tau_points_help)
+                .long("tau-points")
+                .takes_value(true)
+                .default_value("10"),
+        )
         .get_matches();
-    let input_file = matches.value_of("input");
-    input_file.map(str::to_string)
+    let input_filenames = matches
+        .values_of("input")
+        .map(|values| values.map(str::to_string).collect())
+        .unwrap_or_default();
+    let metric = match matches.value_of("metric") {
+        Some("tvar") => Metric::Tvar,
+        Some("tdev") => Metric::Tdev,
+        _ => Metric::Mtie,
+    };
+    let format = match matches.value_of("format") {
+        Some("csv") => Format::Csv,
+        Some("json") => Format::Json,
+        _ => Format::Plain,
+    };
+    let sample_interval = matches.value_of("sample_interval").map(|value| {
+        value
+            .parse::<f64>()
+            .expect("--sample-interval must be a valid number")
+    });
+    let log_spaced = matches.is_present("log_spaced");
+    let tau_points = matches
+        .value_of("tau_points")
+        .unwrap()
+        .parse::<u32>()
+        .expect("--tau-points must be a valid non-negative integer");
+    Arguments {
+        input_filenames,
+        metric,
+        format,
+        sample_interval,
+        log_spaced,
+        tau_points,
+    }
 }
 
-// Reads the TIE input data from the given filename (or standard input),
-// returning the data in one giant String
-fn get_tie_input_data(input_filename: Option<String>) -> anyhow::Result<String> {
-    let buffer = match input_filename {
-        Some(input_filename) => std::fs::read_to_string(&input_filename)
-            .with_context(|| format!("Could not read file '{}'", input_filename))?,
-        None => {
-            let mut buffer = String::new();
-            std::io::stdin().read_to_string(&mut buffer).unwrap();
-            buffer
-        }
-    };
-    Ok(buffer)
+// Opens the given filename (or standard input, if the filename is "-") for buffered reading.
+fn open_tie_input(filename: &str) -> anyhow::Result<Box<dyn BufRead>> {
+    if filename == "-" {
+        Ok(Box::new(BufReader::new(std::io::stdin())))
+    } else {
+        let file = std::fs::File::open(filename)
+            .with_context(|| format!("Could not read file '{}'", filename))?;
+        Ok(Box::new(BufReader::new(file)))
+    }
 }
 
-// Parses the TIE input data, converting from a big giant string,
-// into a vector of TIE values.
-fn parse_tie_input_data(input: String) -> Vec<f64> {
+// Reads the TIE input data from the given filenames, processed in sequence (or from standard
+// input, if no filenames are given), streaming each file's lines through `parse_tie_input_data`
+// instead of slurping the raw input text into one big `String` first. The parsed values are
+// still collected into a single `Vec<f64>` here, though: every metric, including `mtie_fast`,
+// needs the whole data set resident and randomly-accessible to compute, so this only removes the
+// now-unneeded intermediate text buffer -- it doesn't let data sets that don't fit in memory be
+// processed.
+fn get_tie_input_data(input_filenames: &[String]) -> anyhow::Result<Vec<f64>> {
     let mut tie_values = Vec::new();
 
-    let lines: Vec<&str> = input.lines().collect();
-    for (line_number, line) in lines.iter().enumerate() {
+    if input_filenames.is_empty() {
+        let reader = BufReader::new(std::io::stdin());
+        for value in parse_tie_input_data(reader) {
+            tie_values.push(value?);
+        }
+    } else {
+        for filename in input_filenames {
+            let reader = open_tie_input(filename)?;
+            for value in parse_tie_input_data(reader) {
+                tie_values.push(value?);
+            }
+        }
+    }
+
+    Ok(tie_values)
+}
+
+// Parses the TIE input data from a buffered reader, yielding TIE values one line at a time
+// without holding the raw input text in memory. An `Err` is yielded for each line that can't be
+// read, e.g. because the input isn't valid UTF-8; `get_tie_input_data` stops at the first one.
+fn parse_tie_input_data(input: impl BufRead) -> impl Iterator<Item = anyhow::Result<f64>> {
+    input.lines().enumerate().filter_map(|(line_number, line)| {
+        let line_number = line_number + 1; // enumerate starts at 0, but we think of files as starting at line 1.
+        let line = match line {
+            Ok(line) => line,
+            Err(error) => {
+                return Some(Err(
+                    anyhow::Error::new(error).context("failed to read a line of TIE input data")
+                ))
+            }
+        };
         let trimmed = line.trim();
 
         // Ignore comments, which start with a "#" or "//"
-        if trimmed.starts_with('#') || trimmed.starts_with("//") {
-            continue;
+        if trimmed.starts_with('#') || trimmed.starts_with("//") || trimmed.is_empty() {
+            return None;
         }
 
-        if !trimmed.is_empty() {
-            let line_number = line_number + 1; // enumerate starts at 0, but we think of files as starting at line 1.
-            let parse_result = trimmed.parse::<f64>();
-            match parse_result {
-                Ok(number) => tie_values.push(number),
+        match trimmed.parse::<f64>() {
+            Ok(number) => Some(Ok(number)),
 
-                // TODO: Is this error handling sufficient?
-                // It currently simply ignores any invalid input, outputting an error message to standard error.
-                Err(_error) => eprintln!(
+            // TODO: Is this error handling sufficient?
+            // It currently simply ignores any invalid input, outputting an error message to standard error.
+            Err(_error) => {
+                eprintln!(
                     "Ignoring line {} '{}': it does not contain a valid number",
                     line_number, line
-                ),
+                );
+                None
             }
         }
-    }
+    })
+}
 
-    tie_values
+// Prints the calculated metric for each interval in the given format: plain prints two columns
+// (<interval> <value>), csv prints a header row (interval,<metric>) then one comma-separated row
+// per interval, and json prints one array of {"interval":..,"<metric>":..} objects. Each interval
+// is multiplied by `sample_interval`, if given.
+fn print_mtie(mtie: &[(u32, f64)], metric: Metric, format: Format, sample_interval: Option<f64>) {
+    let label = metric.label();
+    let sample_interval = sample_interval.unwrap_or(1.0);
+    let mtie: Vec<(f64, f64)> = mtie
+        .iter()
+        .map(|(interval, val)| (*interval as f64 * sample_interval, *val))
+        .collect();
+
+    match format {
+        Format::Plain => {
+            for (interval, val) in &mtie {
+                println!("{} {}", interval, val);
+            }
+        }
+        Format::Csv => {
+            println!("interval,{}", label);
+            for (interval, val) in &mtie {
+                println!("{},{}", interval, val);
+            }
+        }
+        Format::Json => {
+            let entries: Vec<String> = mtie
+                .iter()
+                .map(|(interval, val)| {
+                    format!("{{\"interval\":{},\"{}\":{}}}", interval, label, val)
+                })
+                .collect();
+            println!("[{}]", entries.join(","));
+        }
+    }
 }
 
-// Prints the MTIE for each interval, in two columns:
-// <interval> <mtie_value>
-fn print_mtie(mtie: &[(u32, f64)]) {
-    for (interval, val) in mtie {
-        println!("{} {}", interval, val);
+// Selects a roughly logarithmically-spaced set of integer taus between 1 and `sample_count` - 1
+// (inclusive), with `points_per_decade` points per decade, for use with `mtie_windowed`.
+fn log_spaced_taus(sample_count: u32, points_per_decade: u32) -> Vec<u32> {
+    let max_tau = sample_count.saturating_sub(1);
+    let mut taus = Vec::new();
+    if max_tau < 1 || points_per_decade == 0 {
+        return taus;
+    }
+
+    let step = 1.0 / points_per_decade as f64;
+    let mut exponent = 0.0;
+    loop {
+        let tau = (10f64.powf(exponent).round() as u32).max(1);
+        if tau > max_tau {
+            break;
+        }
+        if taus.last() != Some(&tau) {
+            taus.push(tau);
+        }
+        exponent += step;
     }
+
+    taus
 }
 
 // Calculates the "complete" MTIE for a series of TIE values
@@ -142,7 +347,10 @@ pub fn mtie_complete(samples: &[f64]) -> Vec<(u32, f64)> {
     mtie
 }
 
-// Calculates the "fast" MTIE for a series of TIE values.
+// Calculates the "fast" MTIE for a series of TIE values. Despite the name, this still requires
+// `samples` to be fully resident in memory: each pyramid level is built by randomly-accessed
+// reads into the level below it, so there's no way to consume `samples` incrementally from a
+// streaming source.
 //
 // See "Fast Algorithms for TVAR and MTIE Computation in Characterization of Network Synchronization Performance"
 // https://citeseerx.ist.psu.edu/viewdoc/download?doi=10.1.1.10.3746&rep=rep1&type=pdf
@@ -197,7 +405,7 @@ pub fn mtie_fast(samples: &[f64]) -> Vec<(u32, f64)> {
     let mut mtie = Vec::new();
     for k in 1..k_max + 1 {
         let i_max = N - 2_u32.pow(k) + 1;
-        let tau = (2_u32.pow(k) - 1) as u32;
+        let tau = 2_u32.pow(k) - 1;
         let k = k as usize;
         let mut mtie_k = a_M[k][1] - a_m[k][1];
         for i in 2..i_max + 1 {
@@ -213,6 +421,139 @@ pub fn mtie_fast(samples: &[f64]) -> Vec<(u32, f64)> {
     mtie
 }
 
+// Calculates the exact MTIE at each of the given taus, using a pair of monotonic deques
+// (of sample indices) per tau to track the max and min of the current window in O(1)
+// amortized per sample, giving an overall O(N * taus.len()) algorithm.
+//
+// Unlike `mtie_fast`, which is restricted to octave taus (2^k - 1), this accepts any
+// caller-supplied set of taus in any order, so it can be used to fill in finer tau resolution.
+// The taus are sorted ascending internally before computing, since MTIE is only guaranteed
+// non-decreasing when observed in ascending tau order.
+pub fn mtie_windowed(samples: &[f64], taus: &[u32]) -> Vec<(u32, f64)> {
+    let count = samples.len();
+    let mut sorted_taus = taus.to_vec();
+    sorted_taus.sort_unstable();
+
+    let mut mtie = Vec::new();
+
+    for &tau in &sorted_taus {
+        let tau = tau as usize;
+        if count <= tau {
+            continue;
+        }
+
+        let mut max_deque: VecDeque<usize> = VecDeque::new();
+        let mut min_deque: VecDeque<usize> = VecDeque::new();
+        let mut window_mtie = 0.0;
+
+        for i in 0..count {
+            while let Some(&back) = max_deque.back() {
+                if samples[back] <= samples[i] {
+                    max_deque.pop_back();
+                } else {
+                    break;
+                }
+            }
+            max_deque.push_back(i);
+
+            while let Some(&back) = min_deque.back() {
+                if samples[back] >= samples[i] {
+                    min_deque.pop_back();
+                } else {
+                    break;
+                }
+            }
+            min_deque.push_back(i);
+
+            while let Some(&front) = max_deque.front() {
+                if front + tau < i {
+                    max_deque.pop_front();
+                } else {
+                    break;
+                }
+            }
+            while let Some(&front) = min_deque.front() {
+                if front + tau < i {
+                    min_deque.pop_front();
+                } else {
+                    break;
+                }
+            }
+
+            if i >= tau {
+                let window_max = samples[*max_deque.front().unwrap()];
+                let window_min = samples[*min_deque.front().unwrap()];
+                let difference = window_max - window_min;
+                if difference > window_mtie {
+                    window_mtie = difference;
+                }
+            }
+        }
+
+        mtie.push((tau as u32, window_mtie));
+    }
+
+    check_monotomically_increasing(&mtie);
+    mtie
+}
+
+// Calculates TVAR (n) = sum_{j=1}^{N-3n+1} [ sum_{i=j}^{j+n-1} (x_{i+2n} - 2*x_{i+n} + x_i) ]^2
+//                        / (6 * n^2 * (N - 3n + 1))
+// for a single observation interval n, using a running sum over the inner sum's window
+// so that the whole sweep over j is O(N).
+fn tvar_for_n(samples: &[f64], n: u32) -> f64 {
+    let count = samples.len() as u32;
+    let n = n as usize;
+
+    // d[i] (0-based) holds the second difference x_{i+2n} - 2*x_{i+n} + x_i for 1-based i = i + 1
+    let d_count = count as usize - 2 * n;
+    let mut d = Vec::with_capacity(d_count);
+    for i in 0..d_count {
+        d.push(samples[i + 2 * n] - 2.0 * samples[i + n] + samples[i]);
+    }
+
+    let j_max = d_count - n + 1;
+    let mut window_sum: f64 = d[0..n].iter().sum();
+    let mut sum_of_squares = window_sum * window_sum;
+    for j in 1..j_max {
+        window_sum = window_sum - d[j - 1] + d[j - 1 + n];
+        sum_of_squares += window_sum * window_sum;
+    }
+
+    sum_of_squares / (6.0 * (n as f64).powi(2) * (j_max as f64))
+}
+
+// Calculates TVAR for a series of TIE values, at octave observation intervals (n = 2^k for
+// k = 0, 1, 2, ...), the same octave spacing `mtie_fast` uses internally (though not the same
+// tau values: `mtie_fast` reports tau = 2^k - 1).
+//
+// See "Fast Algorithms for TVAR and MTIE Computation in Characterization of Network Synchronization Performance"
+// https://citeseerx.ist.psu.edu/viewdoc/download?doi=10.1.1.10.3746&rep=rep1&type=pdf
+pub fn tvar(samples: &[f64]) -> Vec<(u32, f64)> {
+    let count = samples.len() as u32;
+
+    let mut tvar = Vec::new();
+    let mut k = 0;
+    loop {
+        let n = 2_u32.pow(k);
+        if count < 3 * n + 1 {
+            break;
+        }
+        tvar.push((n, tvar_for_n(samples, n)));
+        k += 1;
+    }
+
+    tvar
+}
+
+// Calculates TDEV for a series of TIE values, as the square root of TVAR.
+pub fn tdev(samples: &[f64]) -> Vec<(u32, f64)> {
+    tvar(samples)
+        .into_iter()
+        .map(|(n, variance)| (n, variance.sqrt()))
+        .collect()
+}
+
 fn check_monotomically_increasing(mtie: &[(u32, f64)]) {
     for (index, window) in mtie.windows(2).enumerate() {
         if window[1] < window[0] {
@@ -232,33 +573,34 @@ mod tests {
 
     use super::*;
 
+    fn parse_tie_input_data_str(input: &str) -> Vec<f64> {
+        parse_tie_input_data(std::io::Cursor::new(input))
+            .collect::<anyhow::Result<Vec<f64>>>()
+            .expect("test input is in-memory and cannot produce an I/O error")
+    }
+
     #[test]
     pub fn test_valid_input() {
         // Well formatted input
-        let input = "1.0\n2.0\n3.0".to_string();
-        let numbers = parse_tie_input_data(input);
+        let numbers = parse_tie_input_data_str("1.0\n2.0\n3.0");
         assert_eq!(numbers, vec![1.0, 2.0, 3.0]);
 
         // Same as above, with trailing newline
-        let input = "1.0\n2.0\n3.0\n".to_string();
-        let numbers = parse_tie_input_data(input);
+        let numbers = parse_tie_input_data_str("1.0\n2.0\n3.0\n");
         assert_eq!(numbers, vec![1.0, 2.0, 3.0]);
 
         // Blank lines
-        let input = "1.0\n\n\n\n2.0".to_string();
-        let numbers = parse_tie_input_data(input);
+        let numbers = parse_tie_input_data_str("1.0\n\n\n\n2.0");
         assert_eq!(numbers, vec![1.0, 2.0]);
 
         // Lines with whitespace
-        let input = "1.0\n    \n2.0".to_string();
-        let numbers = parse_tie_input_data(input);
+        let numbers = parse_tie_input_data_str("1.0\n    \n2.0");
         assert_eq!(numbers, vec![1.0, 2.0]);
     }
 
     #[test]
     pub fn test_invalid_input() {
-        let input = "1\nnot_a_number".to_string();
-        let _numbers = parse_tie_input_data(input);
+        let _numbers = parse_tie_input_data_str("1\nnot_a_number");
     }
 
     #[test]
@@ -382,4 +724,84 @@ mod tests {
         let output = mtie_fast(&input);
         assert_eq!(output.len(), 24, "mtie is {:?}", output);
     }
+
+    #[test]
+    pub fn test_tvar_constant() {
+        let input = vec![1234.5678; 10];
+        let output = tvar(&input);
+        assert_eq!(output, vec![(1, 0.0), (2, 0.0)], "tvar for {:?} is {:?}", input, output);
+    }
+
+    #[test]
+    pub fn test_tvar_linear_is_zero() {
+        // The second difference of a linear ramp is zero everywhere, so TVAR should be zero.
+        let input = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+        let output = tvar(&input);
+        assert_eq!(output, vec![(1, 0.0), (2, 0.0)], "tvar for {:?} is {:?}", input, output);
+    }
+
+    #[test]
+    pub fn test_tvar_too_small() {
+        // N must be at least 3n+1, so N=3 is too small even for n=1.
+        let input = vec![0.0; 3];
+        let output = tvar(&input);
+        assert_eq!(output, Vec::new(), "tvar for {:?} is {:?}", input, output);
+    }
+
+    #[test]
+    pub fn test_tdev_is_sqrt_of_tvar() {
+        let input = vec![1.0, 3.0, 2.0, 5.0, 4.0, 6.0, 1.0, 8.0, 3.0, 9.0];
+        let tvar_output = tvar(&input);
+        let tdev_output = tdev(&input);
+        let expected: Vec<(u32, f64)> = tvar_output
+            .into_iter()
+            .map(|(n, variance)| (n, variance.sqrt()))
+            .collect();
+        assert_eq!(tdev_output, expected, "tdev for {:?} is {:?}", input, tdev_output);
+    }
+
+    #[test]
+    pub fn test_windowed_matches_complete() {
+        let input = vec![1.0, 3.0, 2.0, 5.0, 4.0, 6.0, 1.0, 8.0, 3.0, 9.0];
+        let taus: Vec<u32> = (1..input.len() as u32).collect();
+        let expected = mtie_complete(&input);
+        let output = mtie_windowed(&input, &taus);
+        assert_eq!(output, expected, "mtie_windowed for {:?} is {:?}", input, output);
+    }
+
+    #[test]
+    pub fn test_windowed_arbitrary_taus() {
+        let input = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+        let output = mtie_windowed(&input, &[1, 3, 7]);
+        let expected = vec![(1, 1.0), (3, 3.0), (7, 7.0)];
+        assert_eq!(output, expected, "mtie_windowed for {:?} is {:?}", input, output);
+    }
+
+    #[test]
+    pub fn test_windowed_tau_too_large_is_skipped() {
+        let input = vec![1.0, 2.0, 3.0];
+        let output = mtie_windowed(&input, &[1, 5]);
+        assert_eq!(output, vec![(1, 1.0)], "mtie_windowed for {:?} is {:?}", input, output);
+    }
+
+    #[test]
+    pub fn test_windowed_unsorted_taus() {
+        let input = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+        let output = mtie_windowed(&input, &[7, 1, 3]);
+        let expected = vec![(1, 1.0), (3, 3.0), (7, 7.0)];
+        assert_eq!(output, expected, "mtie_windowed for {:?} is {:?}", input, output);
+    }
+
+    #[test]
+    pub fn test_log_spaced_taus() {
+        let taus = log_spaced_taus(1_000, 2);
+        // Two points per decade: 1, ~3, 10, ~32, 100, ~316; the next point (1000) exceeds max_tau (999).
+        assert_eq!(taus, vec![1, 3, 10, 32, 100, 316]);
+    }
+
+    #[test]
+    pub fn test_log_spaced_taus_too_small() {
+        assert_eq!(log_spaced_taus(1, 10), Vec::new());
+        assert_eq!(log_spaced_taus(0, 10), Vec::new());
+    }
 }
@@ -82,6 +82,170 @@ fn test_bad_data_in_file() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn test_multiple_input_files() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = tempdir()?;
+
+    let tmp_file_path_1 = tmp_dir.path().join("tie1");
+    let mut tmp_file_1 = File::create(&tmp_file_path_1)?;
+    writeln!(tmp_file_1, "1.0")?;
+    writeln!(tmp_file_1, "2.1")?;
+
+    let tmp_file_path_2 = tmp_dir.path().join("tie2");
+    let mut tmp_file_2 = File::create(&tmp_file_path_2)?;
+    writeln!(tmp_file_2, "3.2")?;
+
+    let mut cmd = Command::cargo_bin("mtie")?;
+    cmd.arg("--input")
+        .arg(tmp_file_path_1)
+        .arg(tmp_file_path_2);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("1.1"))
+        .stdout(predicate::str::contains("2.2"));
+
+    Ok(())
+}
+
+#[test]
+fn test_csv_format() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = tempdir()?;
+    let tmp_file_path = tmp_dir.path().join("tie");
+    let mut tmp_file = File::create(&tmp_file_path)?;
+    writeln!(tmp_file, "1.0")?;
+    writeln!(tmp_file, "2.1")?;
+    writeln!(tmp_file, "3.2")?;
+
+    let mut cmd = Command::cargo_bin("mtie")?;
+    cmd.arg("--input")
+        .arg(tmp_file_path)
+        .arg("--format")
+        .arg("csv");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("interval,mtie"))
+        .stdout(predicate::str::contains("1,1.1"));
+
+    Ok(())
+}
+
+#[test]
+fn test_json_format() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = tempdir()?;
+    let tmp_file_path = tmp_dir.path().join("tie");
+    let mut tmp_file = File::create(&tmp_file_path)?;
+    writeln!(tmp_file, "1.0")?;
+    writeln!(tmp_file, "2.1")?;
+    writeln!(tmp_file, "3.2")?;
+
+    let mut cmd = Command::cargo_bin("mtie")?;
+    cmd.arg("--input")
+        .arg(tmp_file_path)
+        .arg("--format")
+        .arg("json");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("{\"interval\":1,\"mtie\":1.1}"));
+
+    Ok(())
+}
+
+#[test]
+fn test_csv_format_labels_selected_metric() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = tempdir()?;
+    let tmp_file_path = tmp_dir.path().join("tie");
+    let mut tmp_file = File::create(&tmp_file_path)?;
+    for value in 0..10 {
+        writeln!(tmp_file, "{}", value)?;
+    }
+
+    let mut cmd = Command::cargo_bin("mtie")?;
+    cmd.arg("--input")
+        .arg(tmp_file_path)
+        .arg("--metric")
+        .arg("tvar")
+        .arg("--format")
+        .arg("csv");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("interval,tvar"))
+        .stdout(predicate::str::contains("mtie").not());
+
+    Ok(())
+}
+
+#[test]
+fn test_json_format_labels_selected_metric() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = tempdir()?;
+    let tmp_file_path = tmp_dir.path().join("tie");
+    let mut tmp_file = File::create(&tmp_file_path)?;
+    for value in 0..10 {
+        writeln!(tmp_file, "{}", value)?;
+    }
+
+    let mut cmd = Command::cargo_bin("mtie")?;
+    cmd.arg("--input")
+        .arg(tmp_file_path)
+        .arg("--metric")
+        .arg("tvar")
+        .arg("--format")
+        .arg("json");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\"tvar\":"))
+        .stdout(predicate::str::contains("\"mtie\":").not());
+
+    Ok(())
+}
+
+#[test]
+fn test_sample_interval() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = tempdir()?;
+    let tmp_file_path = tmp_dir.path().join("tie");
+    let mut tmp_file = File::create(&tmp_file_path)?;
+    writeln!(tmp_file, "1.0")?;
+    writeln!(tmp_file, "2.1")?;
+    writeln!(tmp_file, "3.2")?;
+
+    let mut cmd = Command::cargo_bin("mtie")?;
+    cmd.arg("--input")
+        .arg(tmp_file_path)
+        .arg("--sample-interval")
+        .arg("0.5");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("0.5 1.1"))
+        .stdout(predicate::str::contains("1 2.2"));
+
+    Ok(())
+}
+
+#[test]
+fn test_log_spaced_reduces_output_rows() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = tempdir()?;
+    let tmp_file_path = tmp_dir.path().join("tie");
+    let mut tmp_file = File::create(&tmp_file_path)?;
+    for value in 0..100 {
+        writeln!(tmp_file, "{}", value)?;
+    }
+
+    let mut cmd = Command::cargo_bin("mtie")?;
+    cmd.arg("--input")
+        .arg(tmp_file_path)
+        .arg("--log-spaced")
+        .arg("--tau-points")
+        .arg("2");
+    let output = cmd.output()?;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout)?;
+
+    // For a 0..100 ramp, MTIE at tau equals tau (the window max-min grows by exactly 1 per sample).
+    // With --tau-points 2, the log-spaced taus for a 100-sample input are 1, 3, 10, 32.
+    assert_eq!(stdout.lines().collect::<Vec<_>>(), vec!["1 1", "3 3", "10 10", "32 32"]);
+
+    Ok(())
+}
+
 #[test]
 fn test_comments_in_file() -> Result<(), Box<dyn std::error::Error>> {
     let tmp_dir = tempdir()?;